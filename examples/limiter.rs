@@ -9,7 +9,7 @@ pub fn main() {
 
     loop {
         {
-            let frame = frame_counter.start_frame();
+            let mut frame = frame_counter.start_frame();
 
             dummy_workload();
 
@@ -7,6 +7,11 @@ with high-precision timing options.
 - `std_time` (default) - Uses std::time::Instant
 - `quanta` - Uses quanta crate for TSC-based timing
 - `minstant` - Uses minstant crate for TSC-based timing with fallback
+- `qpc` - Uses Windows' `QueryPerformanceCounter` directly, no dependency
+  required; for Windows setups where the TSC-based backends above degrade
+- `std` - Enables the `std::thread`-based sleeping used by
+  [`FrameCounter::sleep_until_framerate`] and [`FrameCounter::tick_fixed`];
+  implied by all of the backends above
 
 Add to Cargo.toml:
 ```toml
@@ -14,6 +19,8 @@ Add to Cargo.toml:
 frame_counter = { version = "*", default-features = false, features = ["quanta"] }
 # or
 frame_counter = { version = "*", default-features = false, features = ["minstant"] }
+# or, on Windows, with no extra dependency
+frame_counter = { version = "*", default-features = false, features = ["qpc"] }
 
 [dependencies]
 quanta = { version = "0.12", optional = true }
@@ -21,11 +28,26 @@ minstant = { version = "0.1", optional = true }
 
 [features]
 default = ["std_time"]
-std_time = []
-quanta = ["dep:quanta"]
-minstant = ["dep:minstant"]
+std = []
+std_time = ["std"]
+quanta = ["dep:quanta", "std"]
+minstant = ["dep:minstant", "std"]
+qpc = ["std"]
 ```
 
+# no_std
+
+With `default-features = false` and none of the backend features above
+enabled, `frame_counter` builds under `no_std` (it still needs `alloc`
+for the rolling frame-time buffer). Construct `FrameCounter<T>` with your
+own [`Timer`] implementation, and use [`FrameCounter::wait_until_framerate`]
+(a pure spin-wait) or [`FrameCounter::sleep_until_framerate_with`] (calls a
+closure you supply - a HAL delay, an RTOS yield, ...) in place of the
+`std`-only [`FrameCounter::sleep_until_framerate`] and
+[`FrameCounter::tick_fixed`]. The frame-time percentile statistics
+(`frame_time_stddev`, `low_1_percent_fps`, `low_01_percent_fps`) also need
+`std`.
+
 # Examples:
 
 Counting the framerate:
@@ -40,166 +62,132 @@ pub fn main() {
     let mut frame_counter = FrameCounter::default();
 
     loop {
-        frame_counter.tick();
-
-        dummy_workload();
+        {
+            let _frame = frame_counter.start_frame();
+            dummy_workload();
+        }
 
         println!("fps stats - {}", frame_counter);
     }
 }
 ```
-*/
-
-pub const INITIAL_FRAMERATE: f64 = 100f64;
-
-use std::fmt;
-
-// Timer abstraction layer
-#[cfg(feature = "std_time")]
-mod timer {
-    use std::time::{Duration, Instant};
 
-    #[derive(Clone, Copy)]
-    pub struct Timer {
-        instant: Instant,
-    }
-
-    impl Timer {
-        pub fn now() -> Self {
-            Timer {
-                instant: Instant::now(),
-            }
-        }
-
-        pub fn duration_since(&self, earlier: &Timer) -> Duration {
-            self.instant.duration_since(earlier.instant)
-        }
-
-        pub fn as_nanos(&self) -> u128 {
-            // For std::time, we can't get absolute nanos, so we use a static reference point
-            static INIT: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
-            let start = INIT.get_or_init(|| Instant::now());
-            self.instant.duration_since(*start).as_nanos()
-        }
-    }
-}
+# Custom timers
 
-#[cfg(feature = "quanta")]
-mod timer {
-    use std::time::Duration;
-
-    #[derive(Clone, Copy)]
-    pub struct Timer {
-        ticks: u64,
-    }
-
-    impl Timer {
-        pub fn now() -> Self {
-            // quanta::Clock uses TSC (Time Stamp Counter) on x86/x86_64
-            // which provides nanosecond-level precision
-            static CLOCK: std::sync::OnceLock<quanta::Clock> = std::sync::OnceLock::new();
-            let clock = CLOCK.get_or_init(|| quanta::Clock::new());
-            Timer { ticks: clock.raw() }
-        }
+`FrameCounter` is generic over the [`Timer`] trait, so it can be driven by
+any clock source instead of the cargo-feature-selected default - for
+example a game engine's frame-synced time source. Use
+[`FrameCounter::with_timer`] to pick one; tests can use [`MockTimer`] this
+way to advance time by hand.
+*/
 
-        pub fn duration_since(&self, earlier: &Timer) -> Duration {
-            static CLOCK: std::sync::OnceLock<quanta::Clock> = std::sync::OnceLock::new();
-            let clock = CLOCK.get_or_init(|| quanta::Clock::new());
+#![cfg_attr(not(feature = "std"), no_std)]
 
-            let delta_ticks = self.ticks.saturating_sub(earlier.ticks);
-            let nanos = clock.delta(earlier.ticks, self.ticks).as_nanos();
-            Duration::from_nanos(nanos as u64)
-        }
+extern crate alloc;
 
-        pub fn as_nanos(&self) -> u128 {
-            static CLOCK: std::sync::OnceLock<quanta::Clock> = std::sync::OnceLock::new();
-            let clock = CLOCK.get_or_init(|| quanta::Clock::new());
-            clock.delta(0, self.ticks).as_nanos()
-        }
-    }
-}
+pub const INITIAL_FRAMERATE: f64 = 100f64;
 
-#[cfg(feature = "minstant")]
-mod timer {
-    use std::time::Duration;
+use alloc::vec::Vec;
+use core::fmt;
 
-    #[derive(Clone, Copy)]
-    pub struct Timer {
-        instant: minstant::Instant,
-    }
+pub mod timer;
 
-    impl Timer {
-        pub fn now() -> Self {
-            // minstant uses TSC on x86/x86_64 with automatic calibration
-            // Falls back to std::time on other platforms
-            Timer {
-                instant: minstant::Instant::now(),
-            }
-        }
+pub use timer::{DefaultTimer, Timer};
+#[cfg(feature = "std")]
+pub use timer::MockTimer;
 
-        pub fn duration_since(&self, earlier: &Timer) -> Duration {
-            self.instant.duration_since(earlier.instant)
-        }
-
-        pub fn as_nanos(&self) -> u128 {
-            self.instant.as_nanos()
-        }
-    }
-}
-
-use timer::Timer;
-
-pub struct FrameCounter {
-    last_tick: Timer,
+pub struct FrameCounter<T: Timer = DefaultTimer> {
+    last_tick: T,
     frame_count: u64,
-    last_frame_time: std::time::Duration,
+    last_frame_time: core::time::Duration,
     last_frame_rate: f64,
-    avg_window_start: Timer,
+    avg_window_start: T,
     avg_frame_count_at_window_start: u64,
-    avg_frame_time: std::time::Duration,
+    avg_frame_time: core::time::Duration,
     avg_frame_rate: f64,
     // For more accurate FPS capping
-    target_frame_start: Option<Timer>,
+    target_frame_start: Option<T>,
     // For even more accurate averaging
     frame_times_buffer: Vec<u64>, // Store last N frame times in nanoseconds
     buffer_index: usize,
+    // Workload accounting: how much of the target frame time was spent
+    // working versus idle/waiting.
+    last_workload: f64,
+    workload_buffer: Vec<f64>,
+    workload_max: f64,
+    // Fixed-timestep bookkeeping (std-only, see `tick_fixed`)
+    #[cfg(feature = "std")]
+    is_behind: bool,
+    #[cfg(feature = "std")]
+    accumulated_lag: core::time::Duration,
+    #[cfg(feature = "std")]
+    max_spiral_multiplier: f64,
 }
 
-impl Default for FrameCounter {
+impl Default for FrameCounter<DefaultTimer> {
     /// Creates a new FrameCounter with a starting framerate of 100.
     fn default() -> Self {
         Self::new(INITIAL_FRAMERATE)
     }
 }
 
-impl FrameCounter {
-    /// Creates a new FrameCounter with the given starting framerate.
+impl FrameCounter<DefaultTimer> {
+    /// Creates a new FrameCounter with the given starting framerate, using
+    /// the cargo-feature-selected [`DefaultTimer`]. Use
+    /// [`FrameCounter::with_timer`] for a custom [`Timer`].
     ///
     /// # Arguments
     /// * `frame_rate` - initial frame rate guess.
     pub fn new(frame_rate: f64) -> Self {
-        let now = Timer::now();
+        Self::with_timer(frame_rate)
+    }
+}
+
+impl<T: Timer> FrameCounter<T> {
+    /// Creates a new FrameCounter with the given starting framerate, driven
+    /// by a caller-chosen [`Timer`] implementation.
+    ///
+    /// # Arguments
+    /// * `frame_rate` - initial frame rate guess.
+    pub fn with_timer(frame_rate: f64) -> Self {
+        let now = T::now();
         // Keep a buffer of frame times for rolling average (1 second at target fps)
         let buffer_size = frame_rate.max(30.0) as usize;
 
         Self {
             last_tick: now,
             frame_count: 0u64,
-            last_frame_time: std::time::Duration::from_secs_f64(1.0 / frame_rate),
+            last_frame_time: core::time::Duration::from_secs_f64(1.0 / frame_rate),
             last_frame_rate: frame_rate,
             avg_window_start: now,
             avg_frame_count_at_window_start: 0u64,
-            avg_frame_time: std::time::Duration::from_secs_f64(1.0 / frame_rate),
+            avg_frame_time: core::time::Duration::from_secs_f64(1.0 / frame_rate),
             avg_frame_rate: frame_rate,
             target_frame_start: None,
-            frame_times_buffer: vec![0u64; buffer_size],
+            frame_times_buffer: alloc::vec![0u64; buffer_size],
             buffer_index: 0,
+            last_workload: 0.0,
+            workload_buffer: alloc::vec![0.0; buffer_size],
+            workload_max: 0.0,
+            #[cfg(feature = "std")]
+            is_behind: false,
+            #[cfg(feature = "std")]
+            accumulated_lag: core::time::Duration::ZERO,
+            #[cfg(feature = "std")]
+            max_spiral_multiplier: 4.0,
         }
     }
 
     /// Updates the frame measurements
     pub fn tick(&mut self) {
-        let now = Timer::now();
+        self.record_tick(None);
+    }
+
+    /// Shared implementation of [`FrameCounter::tick`] and the bookkeeping
+    /// done when a [`FrameGuard`] is dropped. `workload` is `Some(ratio)`
+    /// when the guard tracked a work/wait split for this frame.
+    fn record_tick(&mut self, workload: Option<f64>) {
+        let now = T::now();
 
         // Calculate frame time since last tick with nanosecond precision
         self.last_frame_time = now.duration_since(&self.last_tick);
@@ -207,6 +195,11 @@ impl FrameCounter {
 
         // Store in circular buffer for rolling average
         self.frame_times_buffer[self.buffer_index] = frame_nanos;
+        if let Some(workload) = workload {
+            self.last_workload = workload;
+            self.workload_max = self.workload_max.max(workload);
+        }
+        self.workload_buffer[self.buffer_index] = self.last_workload;
         self.buffer_index = (self.buffer_index + 1) % self.frame_times_buffer.len();
 
         // Calculate instant framerate with higher precision
@@ -220,7 +213,7 @@ impl FrameCounter {
         if self.frame_count >= self.frame_times_buffer.len() as u64 {
             let avg_nanos: u64 =
                 self.frame_times_buffer.iter().sum::<u64>() / self.frame_times_buffer.len() as u64;
-            self.avg_frame_time = std::time::Duration::from_nanos(avg_nanos);
+            self.avg_frame_time = core::time::Duration::from_nanos(avg_nanos);
             self.avg_frame_rate = 1_000_000_000.0 / avg_nanos as f64;
         } else {
             // Still filling buffer, use simple average
@@ -238,8 +231,96 @@ impl FrameCounter {
         self.last_tick = now;
     }
 
+    /// Advances a fixed-timestep simulation, sleeping to pace at
+    /// `target_fps`.
+    ///
+    /// If the last call finished early, sleeps out the remainder of the
+    /// target period (via the same backoff as [`FrameCounter::sleep_until_framerate`])
+    /// so the simulation runs at a constant tick rate. If it ran behind,
+    /// no sleep happens and the real elapsed time is returned instead -
+    /// this is the dt your simulation should consume, not
+    /// [`FrameCounter::avg_frame_time`], which stays the smoothed value
+    /// used by `Display`.
+    ///
+    /// The returned dt is clamped to `max_spiral_multiplier` (default
+    /// `4.0`, see [`FrameCounter::set_max_spiral_multiplier`]) times the
+    /// target frame time, so a single long stall can't cascade into an
+    /// unrecoverable spiral of death. Use [`FrameCounter::is_behind`] and
+    /// [`FrameCounter::accumulated_lag`] to monitor how far behind the
+    /// simulation has fallen.
+    #[cfg(feature = "std")]
+    pub fn tick_fixed(&mut self, target_fps: f64) -> core::time::Duration {
+        let target_period = core::time::Duration::from_secs_f64(1.0 / target_fps);
+        let raw_dt = T::now().duration_since(&self.last_tick);
+
+        if raw_dt < target_period {
+            self.is_behind = false;
+
+            // `sleep_until_framerate_with` paces off `target_frame_start`,
+            // which is only set by `record_tick` - pin it to `last_tick`
+            // (the two always march together post-tick) so this reuses the
+            // exact same backoff as `sleep_until_framerate` instead of a
+            // second copy that could drift out of sync with it.
+            self.target_frame_start = Some(self.last_tick);
+            self.sleep_until_framerate(target_fps);
+        } else {
+            self.is_behind = true;
+            self.accumulated_lag += raw_dt - target_period;
+        }
+
+        self.tick();
+
+        let max_dt = target_period.mul_f64(self.max_spiral_multiplier);
+        self.last_frame_time.min(max_dt)
+    }
+
+    /// Returns whether the last [`FrameCounter::tick_fixed`] call ran
+    /// behind its target period.
+    #[cfg(feature = "std")]
+    pub fn is_behind(&self) -> bool {
+        self.is_behind
+    }
+
+    /// Returns the total accumulated lag (sum of overruns) across all
+    /// [`FrameCounter::tick_fixed`] calls that ran behind schedule.
+    #[cfg(feature = "std")]
+    pub fn accumulated_lag(&self) -> core::time::Duration {
+        self.accumulated_lag
+    }
+
+    /// Sets the multiplier used by [`FrameCounter::tick_fixed`] to clamp
+    /// the returned dt (default `4.0`), preventing a single long stall
+    /// from cascading into a spiral of death.
+    #[cfg(feature = "std")]
+    pub fn set_max_spiral_multiplier(&mut self, multiplier: f64) {
+        self.max_spiral_multiplier = multiplier;
+    }
+
+    /// Starts a new frame, returning a RAII guard that ticks the counter
+    /// when it goes out of scope.
+    ///
+    /// ```no_run
+    /// use frame_counter::FrameCounter;
+    ///
+    /// let mut frame_counter = FrameCounter::default();
+    /// loop {
+    ///     let mut frame = frame_counter.start_frame();
+    ///     // ... do work ...
+    ///     frame.sleep_until_framerate(60.0);
+    /// }
+    /// ```
+    pub fn start_frame(&mut self) -> FrameGuard<'_, T> {
+        FrameGuard {
+            work_start: T::now(),
+            wait_start: None,
+            target_fps: None,
+            counter: self,
+        }
+    }
+
     /// Waits in a hot-loop until the desired frame rate is achieved.
-    /// Uses high-precision timing for accurate frame limiting.
+    /// Uses high-precision timing for accurate frame limiting. Pure
+    /// spin-wait, so this is available even without the `std` feature.
     pub fn wait_until_framerate(&self, framerate: f64) {
         if let Some(frame_start) = self.target_frame_start {
             let target_nanos = (1_000_000_000.0 / framerate) as u128;
@@ -248,57 +329,72 @@ impl FrameCounter {
             let start_nanos = frame_start.as_nanos();
 
             loop {
-                let current_nanos = Timer::now().as_nanos();
+                let current_nanos = T::now().as_nanos();
                 if current_nanos.saturating_sub(start_nanos) >= target_nanos {
                     break;
                 }
 
                 // Yield to prevent excessive CPU cache thrashing
-                std::hint::spin_loop();
+                core::hint::spin_loop();
             }
         }
     }
 
-    /// Waits in a cold-loop until the desired frame rate is achieved.
-    /// Combines sleep with high-precision spin-wait for accuracy.
-    pub fn sleep_until_framerate(&self, framerate: f64) {
+    /// Waits in a cold-loop until the desired frame rate is achieved,
+    /// invoking `on_wait` with the estimated remaining time on each
+    /// iteration instead of sleeping directly. Available without `std` -
+    /// a no_std caller can use `on_wait` to drive a HAL delay or RTOS
+    /// yield; with `std`, [`FrameCounter::sleep_until_framerate`] gives
+    /// you the default OS-aware backoff for free.
+    pub fn sleep_until_framerate_with<F: FnMut(core::time::Duration)>(
+        &self,
+        framerate: f64,
+        mut on_wait: F,
+    ) {
         if let Some(frame_start) = self.target_frame_start {
             let target_nanos = (1_000_000_000.0 / framerate) as u128;
             let start_nanos = frame_start.as_nanos();
 
             loop {
-                let current_nanos = Timer::now().as_nanos();
+                let current_nanos = T::now().as_nanos();
                 let elapsed_nanos = current_nanos.saturating_sub(start_nanos);
 
                 if elapsed_nanos >= target_nanos {
                     break;
                 }
 
-                let remaining_nanos = target_nanos - elapsed_nanos;
-
-                // Sleep for most of the remaining time, but wake up early
-                // to account for sleep imprecision (typically ~1ms on most OSes)
-                if remaining_nanos > 2_000_000 {
-                    // More than 2ms remaining
-                    std::thread::sleep(std::time::Duration::from_micros(500));
-                } else if remaining_nanos > 100_000 {
-                    // 100us to 2ms
-                    std::thread::yield_now(); // Yield to scheduler
-                } else {
-                    // Final precision with spin loop
-                    std::hint::spin_loop();
-                }
+                let remaining_nanos = (target_nanos - elapsed_nanos) as u64;
+                on_wait(core::time::Duration::from_nanos(remaining_nanos));
             }
         }
     }
 
+    /// Waits in a cold-loop until the desired frame rate is achieved.
+    /// Combines sleep with high-precision spin-wait for accuracy.
+    #[cfg(feature = "std")]
+    pub fn sleep_until_framerate(&self, framerate: f64) {
+        self.sleep_until_framerate_with(framerate, |remaining| {
+            // Sleep for most of the remaining time, but wake up early to
+            // account for sleep imprecision (typically ~1ms on most OSes)
+            if remaining > core::time::Duration::from_micros(2000) {
+                std::thread::sleep(core::time::Duration::from_micros(500));
+            } else if remaining > core::time::Duration::from_micros(100) {
+                // Yield to scheduler
+                std::thread::yield_now();
+            } else {
+                // Final precision with spin loop
+                core::hint::spin_loop();
+            }
+        });
+    }
+
     /// Returns the frame time of the last frame as a `Duration`.
-    pub fn frame_time(&self) -> std::time::Duration {
+    pub fn frame_time(&self) -> core::time::Duration {
         self.last_frame_time
     }
 
     /// Returns the average frame time over the rolling window as a `Duration`.
-    pub fn avg_frame_time(&self) -> std::time::Duration {
+    pub fn avg_frame_time(&self) -> core::time::Duration {
         self.avg_frame_time
     }
 
@@ -319,55 +415,307 @@ impl FrameCounter {
 
     /// Returns the timer backend being used
     pub fn timer_backend(&self) -> &'static str {
-        #[cfg(feature = "std_time")]
-        {
-            "std::time::Instant"
+        T::backend_name()
+    }
+
+    /// Returns how much of the last frame's target time was spent working
+    /// versus idle/waiting, as a ratio where `1.0` means the frame's work
+    /// alone filled the entire target frame time.
+    ///
+    /// Only updated for frames started with [`FrameCounter::start_frame`];
+    /// frames ticked manually via [`FrameCounter::tick`] leave this
+    /// unchanged.
+    pub fn workload(&self) -> f64 {
+        self.last_workload
+    }
+
+    /// Returns the average workload ratio over the rolling window.
+    pub fn avg_workload(&self) -> f64 {
+        let len = (self.frame_count as usize).min(self.workload_buffer.len());
+        if len == 0 {
+            return self.last_workload;
         }
-        #[cfg(feature = "quanta")]
-        {
-            "quanta (TSC)"
+        self.workload_buffer.iter().take(len).sum::<f64>() / len as f64
+    }
+
+    /// Returns the highest workload ratio observed since creation.
+    pub fn peak_workload(&self) -> f64 {
+        self.workload_max
+    }
+
+    /// Returns the populated portion of the rolling frame-time buffer,
+    /// sorted ascending in nanoseconds. During warm-up (before the buffer
+    /// has wrapped once) only the first `frame_count` entries are real
+    /// samples, so later slots are excluded.
+    fn sorted_frame_times(&self) -> Vec<u64> {
+        let len = (self.frame_count as usize).min(self.frame_times_buffer.len());
+        let mut times = self.frame_times_buffer[..len].to_vec();
+        times.sort_unstable();
+        times
+    }
+
+    /// Returns the fastest frame time over the rolling window.
+    pub fn min_frame_time(&self) -> core::time::Duration {
+        match self.sorted_frame_times().first() {
+            Some(&nanos) => core::time::Duration::from_nanos(nanos),
+            None => self.last_frame_time,
         }
-        #[cfg(feature = "minstant")]
-        {
-            "minstant (TSC with fallback)"
+    }
+
+    /// Returns the slowest frame time over the rolling window - the worst
+    /// single frame.
+    pub fn max_frame_time(&self) -> core::time::Duration {
+        match self.sorted_frame_times().last() {
+            Some(&nanos) => core::time::Duration::from_nanos(nanos),
+            None => self.last_frame_time,
         }
     }
+
+    /// Returns the standard deviation of frame times over the rolling
+    /// window.
+    ///
+    /// Needs the `std` feature (`no_std` has no `f64::sqrt`).
+    #[cfg(feature = "std")]
+    pub fn frame_time_stddev(&self) -> core::time::Duration {
+        let times = self.sorted_frame_times();
+        if times.is_empty() {
+            return core::time::Duration::ZERO;
+        }
+
+        let mean = times.iter().map(|&n| n as f64).sum::<f64>() / times.len() as f64;
+        let variance = times
+            .iter()
+            .map(|&n| {
+                let diff = n as f64 - mean;
+                diff * diff
+            })
+            .sum::<f64>()
+            / times.len() as f64;
+
+        core::time::Duration::from_nanos(variance.sqrt() as u64)
+    }
+
+    /// Returns the FPS of the mean frame time of the slowest 1% of frames
+    /// over the rolling window - the "1% low" figure benchmark tooling reports.
+    ///
+    /// Needs the `std` feature; see [`FrameCounter::frame_time_stddev`].
+    #[cfg(feature = "std")]
+    pub fn low_1_percent_fps(&self) -> f64 {
+        self.low_percent_fps(0.01)
+    }
+
+    /// Returns the FPS of the mean frame time of the slowest 0.1% of frames
+    /// over the rolling window - the "0.1% low" figure benchmark tooling reports.
+    ///
+    /// Needs the `std` feature; see [`FrameCounter::frame_time_stddev`].
+    #[cfg(feature = "std")]
+    pub fn low_01_percent_fps(&self) -> f64 {
+        self.low_percent_fps(0.001)
+    }
+
+    #[cfg(feature = "std")]
+    fn low_percent_fps(&self, fraction: f64) -> f64 {
+        let times = self.sorted_frame_times();
+        if times.is_empty() {
+            return self.last_frame_rate;
+        }
+
+        let sample_size = ((times.len() as f64 * fraction).ceil() as usize).clamp(1, times.len());
+        let slowest = &times[times.len() - sample_size..];
+        let mean_nanos = slowest.iter().sum::<u64>() as f64 / slowest.len() as f64;
+
+        if mean_nanos > 0.0 {
+            1_000_000_000.0 / mean_nanos
+        } else {
+            0.0
+        }
+    }
+}
+
+/// RAII guard returned by [`FrameCounter::start_frame`]. Ticks the counter
+/// when dropped, so the measured frame time covers everything done while
+/// the guard was alive - including any `sleep_until_framerate` call.
+///
+/// Records when work began (guard creation) and when waiting for the
+/// target frame rate began (the first `wait_until_framerate` /
+/// `sleep_until_framerate` call), so the counter can derive a workload
+/// ratio - see [`FrameCounter::workload`].
+pub struct FrameGuard<'a, T: Timer = DefaultTimer> {
+    counter: &'a mut FrameCounter<T>,
+    work_start: T,
+    wait_start: Option<T>,
+    target_fps: Option<f64>,
 }
 
-impl fmt::Display for FrameCounter {
+impl<T: Timer> FrameGuard<'_, T> {
+    /// See [`FrameCounter::wait_until_framerate`].
+    pub fn wait_until_framerate(&mut self, framerate: f64) {
+        self.mark_wait_start(framerate);
+        self.counter.wait_until_framerate(framerate);
+    }
+
+    /// See [`FrameCounter::sleep_until_framerate`].
+    #[cfg(feature = "std")]
+    pub fn sleep_until_framerate(&mut self, framerate: f64) {
+        self.mark_wait_start(framerate);
+        self.counter.sleep_until_framerate(framerate);
+    }
+
+    /// See [`FrameCounter::sleep_until_framerate_with`].
+    pub fn sleep_until_framerate_with<F: FnMut(core::time::Duration)>(
+        &mut self,
+        framerate: f64,
+        on_wait: F,
+    ) {
+        self.mark_wait_start(framerate);
+        self.counter.sleep_until_framerate_with(framerate, on_wait);
+    }
+
+    fn mark_wait_start(&mut self, framerate: f64) {
+        if self.wait_start.is_none() {
+            self.wait_start = Some(T::now());
+            self.target_fps = Some(framerate);
+        }
+    }
+}
+
+impl<T: Timer> Drop for FrameGuard<'_, T> {
+    fn drop(&mut self) {
+        // If the frame never waited for a target rate, it spent the whole
+        // frame working - a workload of 1.0.
+        let workload = match (self.wait_start, self.target_fps) {
+            (Some(wait_start), Some(target_fps)) => {
+                let work_time = wait_start.duration_since(&self.work_start);
+                let target_frame_time = core::time::Duration::from_secs_f64(1.0 / target_fps);
+                work_time.as_secs_f64() / target_frame_time.as_secs_f64()
+            }
+            _ => 1.0,
+        };
+        self.counter.record_tick(Some(workload));
+    }
+}
+
+impl<T: Timer> fmt::Display for FrameCounter<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "avg: {:.2} fps ({:.3}ms); current: {:.2} fps ({:.3}ms) [{}]",
+            "avg: {:.2} fps ({:.3}ms); current: {:.2} fps ({:.3}ms); workload: {:.1}% (peak {:.1}%) [{}]",
             self.avg_frame_rate(),
             self.avg_frame_time().as_secs_f64() * 1000.0,
             self.frame_rate(),
             self.frame_time().as_secs_f64() * 1000.0,
+            self.avg_workload() * 100.0,
+            self.peak_workload() * 100.0,
             self.timer_backend()
         )
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_frame_counter_accuracy() {
-        let mut fc = FrameCounter::new(60.0);
+        MockTimer::reset();
+        let mut fc = FrameCounter::<MockTimer>::with_timer(60.0);
 
-        // Simulate 60 fps workload
+        // Simulate a perfect 60 fps workload with zero wall-clock waiting.
         for _ in 0..120 {
+            MockTimer::advance(Duration::from_micros(16_667));
             fc.tick();
-            std::thread::sleep(std::time::Duration::from_micros(16_667)); // ~60fps
         }
 
-        // Should be close to 60 fps
         let avg_fps = fc.avg_frame_rate();
         assert!(
-            (avg_fps - 60.0).abs() < 2.0,
+            (avg_fps - 60.0).abs() < 0.1,
             "Average FPS {} not close to 60",
             avg_fps
         );
     }
+
+    #[test]
+    fn test_start_frame_guard_ticks_on_drop() {
+        MockTimer::reset();
+        let mut fc = FrameCounter::<MockTimer>::with_timer(60.0);
+
+        for _ in 0..3 {
+            let frame = fc.start_frame();
+            MockTimer::advance(Duration::from_millis(20));
+            drop(frame);
+        }
+
+        assert_eq!(fc.total_frames(), 3);
+        assert!((fc.frame_rate() - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_workload_tracks_work_vs_wait() {
+        MockTimer::reset();
+        let mut fc = FrameCounter::<MockTimer>::with_timer(60.0);
+
+        // 8ms of work out of a 16ms target (60fps) -> 50% workload.
+        {
+            let mut frame = fc.start_frame();
+            MockTimer::advance(Duration::from_millis(8));
+            frame.sleep_until_framerate(60.0);
+        }
+        assert!((fc.workload() - 0.5).abs() < 0.05, "{}", fc.workload());
+
+        // No waiting at all -> fully busy frame.
+        {
+            let _frame = fc.start_frame();
+            MockTimer::advance(Duration::from_millis(5));
+        }
+        assert!((fc.workload() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tick_fixed_clamps_spiral_of_death() {
+        MockTimer::reset();
+        let mut fc = FrameCounter::<MockTimer>::with_timer(60.0);
+
+        // Always advance past the target period before calling `tick_fixed`
+        // so it takes the "running behind" branch and never spin-waits on a
+        // clock that only moves when we tell it to.
+        MockTimer::advance(Duration::from_millis(20));
+        fc.tick_fixed(60.0);
+        assert!(fc.is_behind());
+
+        // Simulate a huge stall: 10x the 16.667ms target period.
+        MockTimer::advance(Duration::from_millis(166));
+        let dt = fc.tick_fixed(60.0);
+
+        assert!(fc.is_behind());
+        assert!(fc.accumulated_lag() > Duration::ZERO);
+
+        let target_period = Duration::from_secs_f64(1.0 / 60.0);
+        let max_dt = target_period.mul_f64(4.0);
+        assert!(dt <= max_dt, "dt {:?} exceeded spiral clamp {:?}", dt, max_dt);
+    }
+
+    #[test]
+    fn test_percentile_frame_time_stats() {
+        MockTimer::reset();
+        // buffer_size == 30, so one full lap fills it exactly.
+        let mut fc = FrameCounter::<MockTimer>::with_timer(30.0);
+
+        for _ in 0..29 {
+            MockTimer::advance(Duration::from_millis(10));
+            fc.tick();
+        }
+        // One slow frame: a stall well outside the usual 10ms cadence.
+        MockTimer::advance(Duration::from_millis(50));
+        fc.tick();
+
+        assert_eq!(fc.min_frame_time(), Duration::from_millis(10));
+        assert_eq!(fc.max_frame_time(), Duration::from_millis(50));
+        assert!(fc.frame_time_stddev() > Duration::ZERO);
+
+        // Both the 1% and 0.1% low figures fall back to a single worst
+        // frame in a 30-sample window, i.e. the 50ms stall -> 20fps.
+        assert!((fc.low_1_percent_fps() - 20.0).abs() < 0.1);
+        assert!((fc.low_01_percent_fps() - 20.0).abs() < 0.1);
+    }
 }
@@ -1,5 +1,13 @@
-use std::time::{Duration, Instant};
+use core::time::Duration;
 
+/// Abstracts over the clock source used by [`FrameCounter`](crate::FrameCounter).
+///
+/// Implementations represent a single point in time and must be cheap to
+/// copy, since a `FrameCounter` stores several of them (`last_tick`,
+/// `avg_window_start`, `target_frame_start`). Follow `std::time::Instant`'s
+/// contract: `now()` only ever moves forward and `duration_since` /
+/// `as_nanos` are only meaningful relative to other values from the same
+/// implementation.
 pub trait Timer
 where
     Self: Sized + Copy + Clone,
@@ -7,13 +15,22 @@ where
     fn now() -> Self;
     fn duration_since(&self, earlier: &Self) -> Duration;
     fn as_nanos(&self) -> u128;
+
+    /// Human-readable name of the backend, surfaced via
+    /// [`FrameCounter::timer_backend`](crate::FrameCounter::timer_backend).
+    fn backend_name() -> &'static str;
 }
 
+#[cfg(feature = "std_time")]
+use std::time::Instant;
+
+#[cfg(feature = "std_time")]
 #[derive(Clone, Copy)]
 pub struct StdTimer {
     instant: Instant,
 }
 
+#[cfg(feature = "std_time")]
 impl Timer for StdTimer {
     fn now() -> Self {
         Self {
@@ -31,6 +48,10 @@ impl Timer for StdTimer {
         let start = INIT.get_or_init(|| Instant::now());
         self.instant.duration_since(*start).as_nanos()
     }
+
+    fn backend_name() -> &'static str {
+        "std::time::Instant"
+    }
 }
 
 #[cfg(feature = "quanta")]
@@ -62,6 +83,10 @@ impl Timer for QuantaTimer {
         let clock = CLOCK.get_or_init(|| quanta::Clock::new());
         clock.delta(0, self.ticks).as_nanos()
     }
+
+    fn backend_name() -> &'static str {
+        "quanta (TSC)"
+    }
 }
 
 #[cfg(feature = "minstant")]
@@ -89,4 +114,197 @@ impl Timer for MInstantTimer {
     fn as_nanos(&self) -> u128 {
         self.instant.as_unix_nanos(&self.anchor) as u128
     }
+
+    fn backend_name() -> &'static str {
+        "minstant (TSC with fallback)"
+    }
+}
+
+#[cfg(all(feature = "qpc", windows))]
+mod qpc {
+    // Raw FFI so this backend stays dependency-free - std's own Windows
+    // `Instant` measures in QPC units the same way.
+    extern "system" {
+        fn QueryPerformanceCounter(count: *mut i64) -> i32;
+        fn QueryPerformanceFrequency(frequency: *mut i64) -> i32;
+    }
+
+    pub fn now_ticks() -> i64 {
+        let mut ticks = 0i64;
+        unsafe { QueryPerformanceCounter(&mut ticks) };
+        ticks
+    }
+
+    pub fn frequency() -> i64 {
+        static FREQUENCY: std::sync::OnceLock<i64> = std::sync::OnceLock::new();
+        *FREQUENCY.get_or_init(|| {
+            let mut frequency = 0i64;
+            unsafe { QueryPerformanceFrequency(&mut frequency) };
+            frequency
+        })
+    }
+}
+
+/// Windows `QueryPerformanceCounter`-backed timer for setups where the
+/// `quanta`/`minstant` TSC backends degrade (non-x86, no invariant TSC).
+#[cfg(all(feature = "qpc", windows))]
+#[derive(Clone, Copy)]
+pub struct QpcTimer {
+    ticks: i64,
+}
+
+#[cfg(all(feature = "qpc", windows))]
+impl Timer for QpcTimer {
+    fn now() -> Self {
+        Self {
+            ticks: qpc::now_ticks(),
+        }
+    }
+
+    fn duration_since(&self, earlier: &Self) -> Duration {
+        // Mirror std's Windows `Instant`: treat a non-positive delta - which
+        // can happen for near-simultaneous timestamps across cores - as
+        // measurement error and round it down to zero, rather than letting
+        // it appear to run backwards.
+        let delta_ticks = self.ticks - earlier.ticks;
+        if delta_ticks <= 0 {
+            return Duration::ZERO;
+        }
+
+        let nanos = (delta_ticks as u128 * 1_000_000_000) / qpc::frequency() as u128;
+        Duration::from_nanos(nanos as u64)
+    }
+
+    fn as_nanos(&self) -> u128 {
+        if self.ticks <= 0 {
+            return 0;
+        }
+        (self.ticks as u128 * 1_000_000_000) / qpc::frequency() as u128
+    }
+
+    fn backend_name() -> &'static str {
+        "QueryPerformanceCounter (Windows QPC)"
+    }
+}
+
+/// The [`Timer`] backend selected by cargo features; used as the default
+/// type parameter for [`FrameCounter`](crate::FrameCounter).
+#[cfg(feature = "std_time")]
+pub type DefaultTimer = StdTimer;
+
+#[cfg(all(feature = "quanta", not(feature = "std_time")))]
+pub type DefaultTimer = QuantaTimer;
+
+#[cfg(all(
+    feature = "minstant",
+    not(feature = "std_time"),
+    not(feature = "quanta")
+))]
+pub type DefaultTimer = MInstantTimer;
+
+#[cfg(all(
+    feature = "qpc",
+    windows,
+    not(feature = "std_time"),
+    not(feature = "quanta"),
+    not(feature = "minstant")
+))]
+pub type DefaultTimer = QpcTimer;
+
+/// Placeholder used in `no_std` builds, where no backend feature is
+/// enabled. Not a real clock - construct `FrameCounter` with your own
+/// `Timer` implementation instead.
+#[cfg(not(any(
+    feature = "std_time",
+    feature = "quanta",
+    feature = "minstant",
+    all(feature = "qpc", windows)
+)))]
+#[derive(Clone, Copy, Default)]
+pub struct DefaultTimer(u64);
+
+#[cfg(not(any(
+    feature = "std_time",
+    feature = "quanta",
+    feature = "minstant",
+    all(feature = "qpc", windows)
+)))]
+impl Timer for DefaultTimer {
+    fn now() -> Self {
+        static COUNTER: core::sync::atomic::AtomicU64 = core::sync::atomic::AtomicU64::new(0);
+        Self(COUNTER.fetch_add(1, core::sync::atomic::Ordering::Relaxed))
+    }
+
+    fn duration_since(&self, earlier: &Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+
+    fn as_nanos(&self) -> u128 {
+        self.0 as u128
+    }
+
+    fn backend_name() -> &'static str {
+        "none (no_std placeholder - supply your own Timer)"
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static MOCK_NANOS: std::cell::Cell<u128> = const { std::cell::Cell::new(0) };
+}
+
+/// A manually-advanced [`Timer`] for deterministic tests.
+///
+/// All `MockTimer` values on the same thread read from the same
+/// thread-local nanosecond counter, so a test can call [`MockTimer::advance`]
+/// between ticks to script exact frame intervals without any wall-clock
+/// waiting. Needs the `std` feature for its thread-local storage.
+///
+/// ```
+/// use frame_counter::{FrameCounter, MockTimer};
+/// use std::time::Duration;
+///
+/// MockTimer::reset();
+/// let mut fc = FrameCounter::<MockTimer>::with_timer(60.0);
+/// MockTimer::advance(Duration::from_millis(16));
+/// fc.tick();
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockTimer {
+    nanos: u128,
+}
+
+#[cfg(feature = "std")]
+impl MockTimer {
+    /// Advances the shared mock clock by `duration`.
+    pub fn advance(duration: Duration) {
+        MOCK_NANOS.with(|nanos| nanos.set(nanos.get() + duration.as_nanos()));
+    }
+
+    /// Resets the shared mock clock back to zero.
+    pub fn reset() {
+        MOCK_NANOS.with(|nanos| nanos.set(0));
+    }
+}
+
+#[cfg(feature = "std")]
+impl Timer for MockTimer {
+    fn now() -> Self {
+        Self {
+            nanos: MOCK_NANOS.with(|nanos| nanos.get()),
+        }
+    }
+
+    fn duration_since(&self, earlier: &Self) -> Duration {
+        Duration::from_nanos(self.nanos.saturating_sub(earlier.nanos) as u64)
+    }
+
+    fn as_nanos(&self) -> u128 {
+        self.nanos
+    }
+
+    fn backend_name() -> &'static str {
+        "mock (manually advanced)"
+    }
 }